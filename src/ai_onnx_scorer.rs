@@ -3,9 +3,16 @@ use ort::value::Value;
 use std::error::Error;
 use std::path::Path;
 
+use crate::worker::Worker;
+
 // Re-export types from dual_executor module
 pub use crate::dual_executor::{DexData, Pool, Token};
 
+/// Default number of pools per `session.run` call when the model's input
+/// layer pins a fixed batch dimension and the whole-dataset batch is
+/// rejected. Chosen small enough to fit common fixed-batch export shapes.
+const DEFAULT_BATCH_SIZE: usize = 256;
+
 /// Converts a Pool to a feature vector for ONNX input.
 /// You should adapt this function to your model's expected input.
 fn pool_to_features(pool: &Pool) -> Vec<f32> {
@@ -17,16 +24,44 @@ fn pool_to_features(pool: &Pool) -> Vec<f32> {
     ]
 }
 
+/// Stacks `pools` into a single `(pools.len(), feature_len)` input tensor,
+/// runs one `session.run`, and slices the output back into per-pool scores
+/// in the same order as `pools`.
+fn run_batch(session: &mut Session, pools: &[Pool]) -> Result<Vec<f32>, Box<dyn Error>> {
+    let feature_len = pool_to_features(&pools[0]).len();
+    let mut flat = Vec::with_capacity(pools.len() * feature_len);
+    for pool in pools {
+        flat.extend(pool_to_features(pool));
+    }
+
+    let input_array = ndarray::Array2::from_shape_vec((pools.len(), feature_len), flat)?;
+    let input_tensor = Value::from_array(input_array)?;
+
+    let outputs = session.run(ort::inputs![input_tensor])?;
+
+    // try_extract_tensor returns (&Shape, &[T]) in this API version; the
+    // output is one score per row, in the same order as the input rows.
+    let (_shape, data) = outputs[0].try_extract_tensor::<f32>()?;
+    Ok(data.iter().copied().take(pools.len()).collect())
+}
+
 /// Scores all pools using an ONNX model. Returns a Vec of (score, Pool reference).
-/// 
+///
+/// Every pool's feature vector is stacked into a single `(num_pools,
+/// feature_len)` tensor and scored with one `session.run` call, which is
+/// far cheaper than one call per pool for large pool counts. Models that
+/// export a fixed batch dimension and reject the whole-dataset batch fall
+/// back to [`DEFAULT_BATCH_SIZE`]-sized chunks; see
+/// [`score_pools_with_onnx_batched`] to control the chunk size directly.
+///
 /// # Arguments
 /// * `dex_data` - The DEX data containing pools to score
 /// * `model_path` - Path to the ONNX model file
-/// 
+///
 /// # Note
 /// This function uses the ort v2.0.0-rc.10 API. The API may differ in other versions.
 /// Ensure ONNX Runtime is properly installed or enable the `download-binaries` feature.
-/// 
+///
 /// # Example
 /// ```ignore
 /// let dex_data = load_dex_data("dex_data.json")?;
@@ -38,35 +73,82 @@ fn pool_to_features(pool: &Pool) -> Vec<f32> {
 pub fn score_pools_with_onnx<'a>(
     dex_data: &'a DexData,
     model_path: &Path,
+) -> Result<Vec<(f32, &'a Pool)>, Box<dyn Error>> {
+    score_pools_with_onnx_batched(dex_data, model_path, DEFAULT_BATCH_SIZE)
+}
+
+/// Same as [`score_pools_with_onnx`], but lets the caller pick the chunk
+/// size used for the fixed-batch-dimension fallback path.
+pub fn score_pools_with_onnx_batched<'a>(
+    dex_data: &'a DexData,
+    model_path: &Path,
+    batch_size: usize,
 ) -> Result<Vec<(f32, &'a Pool)>, Box<dyn Error>> {
     // Setup ONNX session using v2.0.0-rc.10 API
     // Note: commit_from_file is the correct method for this version
-    let mut session = Session::builder()?
-        .commit_from_file(model_path)?;
+    let mut session = Session::builder()?.commit_from_file(model_path)?;
+
+    let pools = &dex_data.pools;
+    if pools.is_empty() {
+        return Ok(Vec::new());
+    }
 
-    let mut results = Vec::new();
+    // Fast path: score every pool in a single session.run call.
+    match run_batch(&mut session, pools) {
+        Ok(scores) => Ok(scores.into_iter().zip(pools.iter()).collect()),
+        Err(_) => {
+            // Fallback path: the model likely pins a fixed batch dimension
+            // that rejected the whole-dataset batch. Chunk pools into
+            // `batch_size`-sized groups, scoring each with its own
+            // session.run call, and preserve input ordering across chunks.
+            let batch_size = batch_size.max(1);
+            let mut results = Vec::with_capacity(pools.len());
+            for chunk in pools.chunks(batch_size) {
+                let scores = run_batch(&mut session, chunk)?;
+                results.extend(scores.into_iter().zip(chunk.iter()));
+            }
+            Ok(results)
+        }
+    }
+}
 
-    for pool in &dex_data.pools {
-        let features = pool_to_features(pool);
-        let input_array = ndarray::Array2::from_shape_vec(
-            (1, features.len()),
-            features,
-        )?;
-        
-        // Create input tensor value
-        let input_tensor = Value::from_array(input_array)?;
-        
-        // Run inference using ort v2.0.0-rc.10 API
-        // The inputs! macro creates the appropriate input format
-        let outputs = session.run(ort::inputs![input_tensor])?;
-        
-        // Extract score from output tensor
-        // try_extract_tensor returns (&Shape, &[T]) in this API version
-        let (_shape, data) = outputs[0].try_extract_tensor::<f32>()?;
-        let score = data.first().copied().unwrap_or(0.0);
-        results.push((score, pool));
+/// Scores all pools using `num_threads` worker threads, each with its own
+/// ONNX session (an `ort::session::Session` cannot be shared across
+/// threads), and merges the per-thread scores back in input order. See
+/// [`crate::worker::Worker`] for how pools are partitioned across threads.
+///
+/// Passing `num_threads == 1` takes the sequential [`score_pools_with_onnx`]
+/// path directly, with no threads spawned.
+pub fn score_pools_parallel<'a>(
+    dex_data: &'a DexData,
+    model_path: &Path,
+    num_threads: usize,
+) -> Result<Vec<(f32, &'a Pool)>, Box<dyn Error>> {
+    let pools = &dex_data.pools;
+    if pools.is_empty() {
+        return Ok(Vec::new());
     }
-    Ok(results)
+
+    let worker = Worker::new(num_threads);
+    if worker.num_threads() <= 1 {
+        return score_pools_with_onnx(dex_data, model_path);
+    }
+
+    // Errors cross the thread boundary as `String` (not `Box<dyn Error>`,
+    // which isn't `Send`) and are only turned back into a `Box<dyn Error>`
+    // once `try_map_chunks` has returned.
+    let scored = worker
+        .try_map_chunks(pools, |chunk| -> Result<Vec<(f32, &Pool)>, String> {
+            let mut session = Session::builder()
+                .and_then(|b| b.commit_from_file(model_path))
+                .map_err(|e| e.to_string())?;
+            run_batch(&mut session, chunk)
+                .map(|scores| scores.into_iter().zip(chunk.iter()).collect())
+                .map_err(|e| e.to_string())
+        })
+        .map_err(|e| -> Box<dyn Error> { e.into() })?;
+
+    Ok(scored)
 }
 
 #[cfg(test)]
@@ -0,0 +1,321 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::dual_executor::{DexData, Engine};
+use crate::metrics::MetricsRegistry;
+
+/// A detected arbitrage cycle: the token path that forms the round trip
+/// (the first token is repeated at the end to show it closes the loop)
+/// and the net multiplicative gain from executing every swap in the cycle
+/// once, starting and ending with the same token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArbitrageCycle {
+    pub path: Vec<String>,
+    pub gain: f64,
+}
+
+struct Edge {
+    from: usize,
+    to: usize,
+    weight: f64,
+}
+
+/// Treats `DexData` as a directed graph (tokens are nodes, each pool
+/// contributes a token0->token1 and a token1->token0 edge weighted by
+/// `-ln(rate)`) and finds profitable round-trip swaps via Bellman-Ford
+/// negative-cycle detection: a negative-weight cycle means the product of
+/// its exchange rates is greater than one, i.e. a round trip that nets
+/// more tokens than it started with.
+pub struct ArbitrageEngine;
+
+impl ArbitrageEngine {
+    pub fn new() -> Self {
+        ArbitrageEngine
+    }
+
+    /// Builds the token graph from `data` and returns every distinct
+    /// profitable cycle found. Cycles that are rotations of one another
+    /// (same loop, different starting token) are deduplicated.
+    ///
+    /// Naively rerunning Bellman-Ford (`O(V*E)`) from every token would
+    /// cost `O(V^2*E)` total, which doesn't hold up at "thousands of
+    /// pools" scale. Instead, once a negative cycle is found, every token
+    /// reachable from it is skipped as a future source: Bellman-Ford from
+    /// any of them would just walk back into the same cycle (or a rotation
+    /// of it) by construction. This trades strict exhaustiveness - a
+    /// distinct cycle reachable only through an already-covered token could
+    /// in principle go undetected - for the complexity this scale needs.
+    pub fn find_cycles(&self, data: &DexData) -> Vec<ArbitrageCycle> {
+        let (labels, edges) = build_graph(data);
+        let num_tokens = labels.len();
+        if num_tokens == 0 {
+            return Vec::new();
+        }
+
+        let adjacency = build_adjacency(num_tokens, &edges);
+        let mut seen_rotations: HashSet<Vec<usize>> = HashSet::new();
+        let mut covered: HashSet<usize> = HashSet::new();
+        let mut cycles = Vec::new();
+
+        for source in 0..num_tokens {
+            if covered.contains(&source) {
+                continue;
+            }
+            if let Some((cycle_indices, sum_weights)) =
+                bellman_ford_negative_cycle(num_tokens, &edges, source)
+            {
+                mark_reachable(&adjacency, &cycle_indices, &mut covered);
+
+                let key = canonical_rotation(&cycle_indices);
+                if key.is_empty() || !seen_rotations.insert(key) {
+                    continue;
+                }
+                let path = cycle_indices.iter().map(|&i| labels[i].clone()).collect();
+                cycles.push(ArbitrageCycle { path, gain: (-sum_weights).exp() });
+            }
+        }
+
+        cycles
+    }
+}
+
+impl Engine for ArbitrageEngine {
+    fn execute(&self, data: &DexData, _metrics: &MetricsRegistry) {
+        let cycles = self.find_cycles(data);
+        println!("ArbitrageEngine: found {} profitable cycle(s)", cycles.len());
+        for cycle in &cycles {
+            println!("  {} - gain x{:.6}", cycle.path.join(" -> "), cycle.gain);
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "ArbitrageEngine"
+    }
+}
+
+/// Builds the token graph directly from the pools: nodes are keyed by
+/// token address (not symbol, since duplicate symbols across distinct
+/// addresses must not collapse into the same node) and labeled with the
+/// token's symbol when known, falling back to its address otherwise.
+/// Pools with a zero reserve on either side are skipped since they carry
+/// no usable exchange rate.
+fn build_graph(data: &DexData) -> (Vec<String>, Vec<Edge>) {
+    let mut address_to_symbol: HashMap<&str, &str> = HashMap::new();
+    for token in &data.tokens {
+        address_to_symbol.entry(token.address.as_str()).or_insert(token.symbol.as_str());
+    }
+
+    let mut index_of: HashMap<String, usize> = HashMap::new();
+    let mut labels: Vec<String> = Vec::new();
+    let mut edges: Vec<Edge> = Vec::new();
+
+    for pool in &data.pools {
+        if pool.reserve0 == 0 || pool.reserve1 == 0 {
+            continue;
+        }
+
+        let fee_frac = pool.fee as f64 / 1_000_000.0;
+        let reserve0 = pool.reserve0 as f64;
+        let reserve1 = pool.reserve1 as f64;
+
+        let rate_0_to_1 = (reserve1 / reserve0) * (1.0 - fee_frac);
+        let rate_1_to_0 = (reserve0 / reserve1) * (1.0 - fee_frac);
+        if rate_0_to_1 <= 0.0 || rate_1_to_0 <= 0.0 {
+            continue;
+        }
+
+        let idx0 = node_index(&pool.token0, &address_to_symbol, &mut index_of, &mut labels);
+        let idx1 = node_index(&pool.token1, &address_to_symbol, &mut index_of, &mut labels);
+
+        edges.push(Edge { from: idx0, to: idx1, weight: -rate_0_to_1.ln() });
+        edges.push(Edge { from: idx1, to: idx0, weight: -rate_1_to_0.ln() });
+    }
+
+    (labels, edges)
+}
+
+/// Builds an outgoing-edge adjacency list, indexed by node, for the
+/// reachability walk in [`mark_reachable`].
+fn build_adjacency(num_tokens: usize, edges: &[Edge]) -> Vec<Vec<usize>> {
+    let mut adjacency = vec![Vec::new(); num_tokens];
+    for edge in edges {
+        adjacency[edge.from].push(edge.to);
+    }
+    adjacency
+}
+
+/// Marks every node in `starts`, and every node reachable from them via
+/// `adjacency`, as covered - used to skip Bellman-Ford sources that would
+/// just rediscover an already-found cycle.
+fn mark_reachable(adjacency: &[Vec<usize>], starts: &[usize], covered: &mut HashSet<usize>) {
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for &start in starts {
+        if covered.insert(start) {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        for &next in &adjacency[node] {
+            if covered.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+}
+
+fn node_index(
+    address: &str,
+    address_to_symbol: &HashMap<&str, &str>,
+    index_of: &mut HashMap<String, usize>,
+    labels: &mut Vec<String>,
+) -> usize {
+    if let Some(&idx) = index_of.get(address) {
+        return idx;
+    }
+    let idx = labels.len();
+    let label = address_to_symbol.get(address).copied().unwrap_or(address).to_string();
+    labels.push(label);
+    index_of.insert(address.to_string(), idx);
+    idx
+}
+
+/// Runs Bellman-Ford from `source`: relaxes every edge `num_tokens - 1`
+/// times, then performs one extra relaxation pass. Any node still
+/// relaxable in that extra pass lies on, or is reachable from, a
+/// negative-weight cycle. Recovers the cycle by walking predecessor
+/// pointers backward, first stepping `num_tokens` times to guarantee
+/// landing inside the cycle, then following predecessors until a node
+/// repeats. Returns the cycle (token indices, first repeated as the last
+/// entry) along with the sum of its edge weights.
+fn bellman_ford_negative_cycle(
+    num_tokens: usize,
+    edges: &[Edge],
+    source: usize,
+) -> Option<(Vec<usize>, f64)> {
+    let mut dist = vec![f64::INFINITY; num_tokens];
+    let mut pred: Vec<Option<usize>> = vec![None; num_tokens];
+    let mut pred_weight = vec![0.0_f64; num_tokens];
+    dist[source] = 0.0;
+
+    let mut relaxed_node = None;
+    for pass in 0..num_tokens {
+        relaxed_node = None;
+        for edge in edges {
+            if dist[edge.from] == f64::INFINITY {
+                continue;
+            }
+            let candidate = dist[edge.from] + edge.weight;
+            if candidate < dist[edge.to] - 1e-12 {
+                dist[edge.to] = candidate;
+                pred[edge.to] = Some(edge.from);
+                pred_weight[edge.to] = edge.weight;
+                if pass == num_tokens - 1 {
+                    relaxed_node = Some(edge.to);
+                }
+            }
+        }
+    }
+
+    let mut node = relaxed_node?;
+    for _ in 0..num_tokens {
+        node = pred[node]?;
+    }
+
+    // Walk predecessors, collecting nodes in reverse travel order, until a
+    // node repeats - that repeat marks the start/end of the cycle.
+    let mut walk = vec![node];
+    let mut seen = HashSet::new();
+    seen.insert(node);
+    let mut cur = node;
+    let repeat = loop {
+        cur = pred[cur]?;
+        walk.push(cur);
+        if !seen.insert(cur) {
+            break cur;
+        }
+    };
+
+    let start = walk.iter().position(|&n| n == repeat)?;
+    let mut cycle: Vec<usize> = walk[start..].to_vec();
+    // `cycle` is closed (first == last); sum each edge's weight once by
+    // skipping the duplicated closing entry.
+    let sum_weights: f64 = cycle[..cycle.len() - 1].iter().map(|&n| pred_weight[n]).sum();
+    cycle.reverse();
+
+    Some((cycle, sum_weights))
+}
+
+/// Canonicalizes a closed cycle (first index repeated as the last entry)
+/// so that rotations of the same loop compare equal: drops the closing
+/// repeat and rotates the remaining indices to start at the smallest one,
+/// preserving travel direction.
+fn canonical_rotation(cycle: &[usize]) -> Vec<usize> {
+    if cycle.len() <= 1 {
+        return Vec::new();
+    }
+    let core = &cycle[..cycle.len() - 1];
+    let min_pos = core
+        .iter()
+        .enumerate()
+        .min_by_key(|&(_, v)| *v)
+        .map(|(i, _)| i)
+        .unwrap();
+    core.iter().cycle().skip(min_pos).take(core.len()).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_executor::{Pool, Token};
+
+    fn token(symbol: &str, address: &str) -> Token {
+        Token { symbol: symbol.to_string(), decimals: 18, address: address.to_string() }
+    }
+
+    fn pool(token0: &str, token1: &str, reserve0: u64, reserve1: u64, fee: u64) -> Pool {
+        Pool {
+            dexName: "TestDex".to_string(),
+            chain: "TestChain".to_string(),
+            token0: token0.to_string(),
+            token1: token1.to_string(),
+            reserve0,
+            reserve1,
+            fee,
+        }
+    }
+
+    #[test]
+    fn no_cycle_for_a_single_consistent_pool() {
+        let data = DexData {
+            tokens: vec![token("A", "0xA"), token("B", "0xB")],
+            pools: vec![pool("0xA", "0xB", 1_000_000, 1_000_000, 3000)],
+        };
+        let cycles = ArbitrageEngine::new().find_cycles(&data);
+        assert!(cycles.is_empty());
+    }
+
+    #[test]
+    fn detects_a_three_token_round_trip() {
+        // A -> B -> C -> A with mispriced rates that compound to a net gain.
+        let data = DexData {
+            tokens: vec![token("A", "0xA"), token("B", "0xB"), token("C", "0xC")],
+            pools: vec![
+                pool("0xA", "0xB", 1_000, 2_000, 0),
+                pool("0xB", "0xC", 1_000, 2_000, 0),
+                pool("0xC", "0xA", 1_000, 2_000, 0),
+            ],
+        };
+        let cycles = ArbitrageEngine::new().find_cycles(&data);
+        assert!(!cycles.is_empty());
+        assert!(cycles.iter().all(|c| c.gain > 1.0));
+    }
+
+    #[test]
+    fn zero_reserve_pools_are_skipped() {
+        let data = DexData {
+            tokens: vec![token("A", "0xA"), token("B", "0xB")],
+            pools: vec![pool("0xA", "0xB", 0, 1_000_000, 3000)],
+        };
+        let cycles = ArbitrageEngine::new().find_cycles(&data);
+        assert!(cycles.is_empty());
+    }
+}
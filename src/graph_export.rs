@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::dual_executor::{DexData, Engine};
+use crate::metrics::MetricsRegistry;
+
+/// Whether [`to_dot`] renders a directed `digraph` (one edge per swap
+/// direction, i.e. two edges per pool) or an undirected `graph` (one edge
+/// per pool, direction-agnostic).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Digraph,
+    Graph,
+}
+
+/// Renders the token/pool graph from `data` in GraphViz DOT format: one
+/// node per unique token (labeled with its symbol when known, falling
+/// back to its address), and one edge per pool - two, in opposite
+/// directions, for [`Kind::Digraph`] - labeled with the pool's dex name,
+/// chain, and reserve ratio. Tokens that appear in `data.tokens` but in no
+/// pool are still emitted as nodes, so isolated tokens are visible rather
+/// than silently dropped.
+pub fn to_dot(data: &DexData, kind: Kind) -> String {
+    let mut address_to_symbol: HashMap<&str, &str> = HashMap::new();
+    for token in &data.tokens {
+        address_to_symbol.entry(token.address.as_str()).or_insert(token.symbol.as_str());
+    }
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut nodes: Vec<&str> = Vec::new();
+    for token in &data.tokens {
+        if seen.insert(token.address.as_str()) {
+            nodes.push(token.address.as_str());
+        }
+    }
+    for pool in &data.pools {
+        if seen.insert(pool.token0.as_str()) {
+            nodes.push(pool.token0.as_str());
+        }
+        if seen.insert(pool.token1.as_str()) {
+            nodes.push(pool.token1.as_str());
+        }
+    }
+
+    let (graph_kw, edge_op) = match kind {
+        Kind::Digraph => ("digraph", "->"),
+        Kind::Graph => ("graph", "--"),
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!("{graph_kw} {{\n"));
+
+    for address in &nodes {
+        let label = address_to_symbol.get(address).copied().unwrap_or(address);
+        out.push_str(&format!("  \"{address}\" [label=\"{label}\"];\n"));
+    }
+
+    for pool in &data.pools {
+        let ratio = reserve_ratio(pool.reserve0, pool.reserve1);
+        out.push_str(&edge_line(pool.token0.as_str(), pool.token1.as_str(), edge_op, pool, ratio));
+
+        if kind == Kind::Digraph {
+            let reverse_ratio = reserve_ratio(pool.reserve1, pool.reserve0);
+            out.push_str(&edge_line(pool.token1.as_str(), pool.token0.as_str(), edge_op, pool, reverse_ratio));
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn reserve_ratio(reserve_in: u64, reserve_out: u64) -> f64 {
+    if reserve_out == 0 {
+        f64::INFINITY
+    } else {
+        reserve_in as f64 / reserve_out as f64
+    }
+}
+
+fn edge_line(from: &str, to: &str, edge_op: &str, pool: &crate::dual_executor::Pool, ratio: f64) -> String {
+    format!(
+        "  \"{from}\" {edge_op} \"{to}\" [label=\"{} ({}) ratio={:.4}\"];\n",
+        pool.dexName, pool.chain, ratio
+    )
+}
+
+/// An [`Engine`] that prints the pool topology as a GraphViz DOT graph,
+/// e.g. for piping into `dot -Tpng` to visually inspect liquidity
+/// connectivity and spot isolated tokens before running the arbitrage or
+/// scoring engines.
+pub struct GraphExportEngine {
+    pub kind: Kind,
+}
+
+impl GraphExportEngine {
+    pub fn new(kind: Kind) -> Self {
+        GraphExportEngine { kind }
+    }
+}
+
+impl Engine for GraphExportEngine {
+    fn execute(&self, data: &DexData, _metrics: &MetricsRegistry) {
+        println!("{}", to_dot(data, self.kind));
+    }
+
+    fn name(&self) -> &'static str {
+        "GraphExportEngine"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_executor::{Pool, Token};
+
+    fn sample_data() -> DexData {
+        DexData {
+            tokens: vec![
+                Token { symbol: "A".to_string(), decimals: 18, address: "0xA".to_string() },
+                Token { symbol: "B".to_string(), decimals: 18, address: "0xB".to_string() },
+                Token { symbol: "C".to_string(), decimals: 18, address: "0xC".to_string() },
+            ],
+            pools: vec![Pool {
+                dexName: "TestDex".to_string(),
+                chain: "TestChain".to_string(),
+                token0: "0xA".to_string(),
+                token1: "0xB".to_string(),
+                reserve0: 1_000,
+                reserve1: 2_000,
+                fee: 3000,
+            }],
+        }
+    }
+
+    #[test]
+    fn digraph_emits_both_swap_directions() {
+        let dot = to_dot(&sample_data(), Kind::Digraph);
+        assert!(dot.starts_with("digraph {"));
+        assert_eq!(dot.matches("->").count(), 2);
+        assert!(dot.contains("\"0xA\" -> \"0xB\""));
+        assert!(dot.contains("\"0xB\" -> \"0xA\""));
+    }
+
+    #[test]
+    fn graph_emits_one_edge_per_pool() {
+        let dot = to_dot(&sample_data(), Kind::Graph);
+        assert!(dot.starts_with("graph {"));
+        assert_eq!(dot.matches("--").count(), 1);
+    }
+
+    #[test]
+    fn isolated_tokens_are_still_emitted_as_nodes() {
+        let dot = to_dot(&sample_data(), Kind::Digraph);
+        assert!(dot.contains("\"0xC\" [label=\"C\"]"));
+    }
+}
@@ -1,6 +1,16 @@
 pub mod dual_executor;
 pub mod ai_onnx_scorer;
+pub mod worker;
+pub mod arbitrage_engine;
+pub mod model_backend;
+pub mod metrics;
+pub mod graph_export;
 
 // Re-export commonly used types
 pub use dual_executor::{Token, Pool, DexData, Engine, SummaryEngine, TopPoolEngine, DualExecutor};
 pub use ai_onnx_scorer::score_pools_with_onnx;
+pub use worker::Worker;
+pub use arbitrage_engine::{ArbitrageEngine, ArbitrageCycle};
+pub use model_backend::{ModelBackend, OnnxBackend, TensorFlowBackend, load_backend, load_custom_op};
+pub use metrics::{MetricsRegistry, MetricsSnapshot};
+pub use graph_export::{to_dot, Kind, GraphExportEngine};
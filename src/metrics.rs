@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Sorted `(key, value)` label pairs attached to a metric series. Sorted so
+/// that the same logical label set always hashes/compares equal regardless
+/// of the order callers passed them in.
+type Labels = Vec<(String, String)>;
+
+fn normalize_labels(labels: &[(&str, &str)]) -> Labels {
+    let mut owned: Labels = labels.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect();
+    owned.sort();
+    owned
+}
+
+/// Renders a label set as Prometheus's `{k="v",k2="v2"}` syntax, or an
+/// empty string when there are no labels. Quotes and backslashes in values
+/// are escaped since they'd otherwise produce invalid exposition output.
+fn format_labels(labels: &[(String, String)]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = labels
+        .iter()
+        .map(|(k, v)| format!("{k}=\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Histogram {
+    count: u64,
+    sum: f64,
+}
+
+/// A lightweight metrics registry for the engine execution pipeline:
+/// counters (pools scored, engines run), gauges (loaded model version),
+/// and histograms (per-engine latency). Each metric is identified by a
+/// name plus an optional label set - e.g. `engine_duration_seconds` with
+/// label `engine="AIScorerEngine"` - kept as real key/value pairs rather
+/// than baked into the metric name string, so the name alone always
+/// satisfies Prometheus's `[a-zA-Z_:][a-zA-Z0-9_:]*` naming rule.
+///
+/// All state lives behind a mutex per metric kind since recording happens
+/// off the hot path of scoring itself, where a little lock contention is
+/// an acceptable trade for simplicity.
+#[derive(Default)]
+pub struct MetricsRegistry {
+    counters: Mutex<HashMap<(String, Labels), u64>>,
+    gauges: Mutex<HashMap<(String, Labels), f64>>,
+    histograms: Mutex<HashMap<(String, Labels), Histogram>>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments an unlabeled counter by `delta`.
+    pub fn incr_counter(&self, name: &str, delta: u64) {
+        self.incr_counter_with_labels(name, &[], delta);
+    }
+
+    /// Increments a counter by `delta`, keyed by `name` plus `labels`.
+    pub fn incr_counter_with_labels(&self, name: &str, labels: &[(&str, &str)], delta: u64) {
+        let key = (name.to_string(), normalize_labels(labels));
+        *self.counters.lock().unwrap().entry(key).or_insert(0) += delta;
+    }
+
+    /// Sets an unlabeled gauge to `value`.
+    pub fn set_gauge(&self, name: &str, value: f64) {
+        self.set_gauge_with_labels(name, &[], value);
+    }
+
+    /// Sets a gauge to `value`, keyed by `name` plus `labels`.
+    pub fn set_gauge_with_labels(&self, name: &str, labels: &[(&str, &str)], value: f64) {
+        let key = (name.to_string(), normalize_labels(labels));
+        self.gauges.lock().unwrap().insert(key, value);
+    }
+
+    /// Adds a single observation to an unlabeled histogram.
+    pub fn observe_histogram(&self, name: &str, value: f64) {
+        self.observe_histogram_with_labels(name, &[], value);
+    }
+
+    /// Adds a single observation to a histogram keyed by `name` plus
+    /// `labels`.
+    pub fn observe_histogram_with_labels(&self, name: &str, labels: &[(&str, &str)], value: f64) {
+        let key = (name.to_string(), normalize_labels(labels));
+        let mut histograms = self.histograms.lock().unwrap();
+        let entry = histograms.entry(key).or_default();
+        entry.count += 1;
+        entry.sum += value;
+    }
+
+    /// Times `f`, recording its wall-clock duration in seconds into the
+    /// named histogram, and returns `f`'s result.
+    pub fn time_histogram<T>(&self, name: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.observe_histogram(name, start.elapsed().as_secs_f64());
+        result
+    }
+
+    /// A point-in-time snapshot of every counter, gauge, and histogram
+    /// currently registered.
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            counters: self.counters.lock().unwrap().clone(),
+            gauges: self.gauges.lock().unwrap().clone(),
+            histograms: self
+                .histograms
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(key, h)| (key.clone(), (h.count, h.sum)))
+                .collect(),
+        }
+    }
+
+    /// Renders the current metrics in Prometheus text exposition format.
+    pub fn export_prometheus(&self) -> String {
+        self.metrics_snapshot().to_prometheus()
+    }
+}
+
+/// An immutable point-in-time copy of [`MetricsRegistry`]'s state. Each map
+/// is keyed by `(metric name, sorted labels)`.
+#[derive(Debug, Default, Clone)]
+pub struct MetricsSnapshot {
+    pub counters: HashMap<(String, Labels), u64>,
+    pub gauges: HashMap<(String, Labels), f64>,
+    /// (metric name, labels) -> (observation count, sum of observed values)
+    pub histograms: HashMap<(String, Labels), (u64, f64)>,
+}
+
+impl MetricsSnapshot {
+    /// Renders this snapshot in Prometheus text exposition format: one
+    /// `# TYPE` line per metric *name* (not per label variant), followed
+    /// by one sample line per label variant. Histograms are exposed as
+    /// `_count` and `_sum` series since no bucket boundaries are tracked.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&render_samples(&self.counters, "counter", |name, labels, value| {
+            format!("{name}{} {value}\n", format_labels(labels))
+        }));
+        out.push_str(&render_samples(&self.gauges, "gauge", |name, labels, value| {
+            format!("{name}{} {value}\n", format_labels(labels))
+        }));
+        out.push_str(&render_samples(&self.histograms, "histogram", |name, labels, (count, sum)| {
+            let label_str = format_labels(labels);
+            format!("{name}_count{label_str} {count}\n{name}_sum{label_str} {sum}\n")
+        }));
+        out
+    }
+}
+
+/// Groups `series` by metric name, emitting one `# TYPE name type` line per
+/// name followed by `render_sample` for each of its label variants (in a
+/// stable, sorted order so output is deterministic).
+fn render_samples<V: Copy>(
+    series: &HashMap<(String, Labels), V>,
+    type_name: &str,
+    render_sample: impl Fn(&str, &[(String, String)], V) -> String,
+) -> String {
+    let mut names: Vec<&String> = series.keys().map(|(name, _)| name).collect();
+    names.sort();
+    names.dedup();
+
+    let mut out = String::new();
+    for name in names {
+        out.push_str(&format!("# TYPE {name} {type_name}\n"));
+        let mut entries: Vec<(&Labels, V)> =
+            series.iter().filter(|((n, _), _)| n == name).map(|((_, labels), v)| (labels, *v)).collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        for (labels, value) in entries {
+            out.push_str(&render_sample(name, labels, value));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_and_gauges_accumulate() {
+        let metrics = MetricsRegistry::new();
+        metrics.incr_counter("engines_run_total", 1);
+        metrics.incr_counter("engines_run_total", 2);
+        metrics.set_gauge("loaded_model_version", 3.0);
+
+        let snapshot = metrics.metrics_snapshot();
+        assert_eq!(snapshot.counters.get(&("engines_run_total".to_string(), vec![])), Some(&3));
+        assert_eq!(snapshot.gauges.get(&("loaded_model_version".to_string(), vec![])), Some(&3.0));
+    }
+
+    #[test]
+    fn labels_keep_distinct_series_separate() {
+        let metrics = MetricsRegistry::new();
+        metrics.incr_counter_with_labels("engine_executions_total", &[("engine", "SummaryEngine")], 1);
+        metrics.incr_counter_with_labels("engine_executions_total", &[("engine", "TopPoolEngine")], 1);
+        metrics.incr_counter_with_labels("engine_executions_total", &[("engine", "SummaryEngine")], 1);
+
+        let snapshot = metrics.metrics_snapshot();
+        let summary_key = ("engine_executions_total".to_string(), vec![("engine".to_string(), "SummaryEngine".to_string())]);
+        let top_pool_key = ("engine_executions_total".to_string(), vec![("engine".to_string(), "TopPoolEngine".to_string())]);
+        assert_eq!(snapshot.counters.get(&summary_key), Some(&2));
+        assert_eq!(snapshot.counters.get(&top_pool_key), Some(&1));
+    }
+
+    #[test]
+    fn histogram_tracks_count_and_sum() {
+        let metrics = MetricsRegistry::new();
+        metrics.observe_histogram("engine_duration_seconds", 0.5);
+        metrics.observe_histogram("engine_duration_seconds", 1.5);
+
+        let snapshot = metrics.metrics_snapshot();
+        assert_eq!(
+            snapshot.histograms.get(&("engine_duration_seconds".to_string(), vec![])),
+            Some(&(2, 2.0))
+        );
+    }
+
+    #[test]
+    fn prometheus_export_uses_real_labels_not_baked_into_the_name() {
+        let metrics = MetricsRegistry::new();
+        metrics.incr_counter_with_labels("engine_executions_total", &[("engine", "SummaryEngine")], 1);
+        let exported = metrics.export_prometheus();
+
+        assert!(exported.contains("# TYPE engine_executions_total counter"));
+        assert!(exported.contains("engine_executions_total{engine=\"SummaryEngine\"} 1"));
+        assert!(!exported.contains("# TYPE engine_executions_total{"));
+    }
+
+    #[test]
+    fn prometheus_export_emits_one_type_line_per_metric_name() {
+        let metrics = MetricsRegistry::new();
+        metrics.incr_counter_with_labels("engine_executions_total", &[("engine", "SummaryEngine")], 1);
+        metrics.incr_counter_with_labels("engine_executions_total", &[("engine", "TopPoolEngine")], 1);
+        let exported = metrics.export_prometheus();
+
+        assert_eq!(exported.matches("# TYPE engine_executions_total counter").count(), 1);
+    }
+}
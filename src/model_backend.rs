@@ -0,0 +1,201 @@
+use ndarray::Array2;
+use ort::session::Session;
+use ort::value::Value;
+use std::error::Error;
+use std::path::Path;
+
+use crate::dual_executor::Pool;
+
+/// Converts a Pool to a feature vector used as model input. Shared by
+/// every [`ModelBackend`] implementation so the feature layout stays
+/// consistent no matter which runtime scores it.
+fn pool_to_features(pool: &Pool) -> Vec<f32> {
+    vec![pool.reserve0 as f32, pool.reserve1 as f32, pool.fee as f32]
+}
+
+/// Stacks every pool's feature vector into a single `(pools.len(),
+/// feature_len)` matrix, in input order.
+fn features_matrix(pools: &[Pool]) -> Result<Array2<f32>, Box<dyn Error>> {
+    if pools.is_empty() {
+        return Ok(Array2::from_shape_vec((0, 0), Vec::new())?);
+    }
+    let feature_len = pool_to_features(&pools[0]).len();
+    let mut flat = Vec::with_capacity(pools.len() * feature_len);
+    for pool in pools {
+        flat.extend(pool_to_features(pool));
+    }
+    Ok(Array2::from_shape_vec((pools.len(), feature_len), flat)?)
+}
+
+/// A pluggable scoring backend. Implementations wrap a specific inference
+/// runtime (ONNX Runtime, TensorFlow, ...) behind a single batch-scoring
+/// call so callers such as `AIScorerEngine` don't need to know which
+/// runtime actually loaded the model.
+///
+/// `score_batch` takes `&mut self` because the underlying runtime session
+/// (`ort::session::Session`, `tensorflow::Session`) is not safe to share
+/// across threads; callers that want to score in parallel should load one
+/// backend instance per thread (see `AIScorerEngine::execute`) rather than
+/// guard a single shared instance behind a lock.
+pub trait ModelBackend: Send {
+    /// Loads a model from `path`.
+    fn load(path: &Path) -> Result<Self, Box<dyn Error>>
+    where
+        Self: Sized;
+
+    /// Scores a `(num_rows, feature_len)` batch, returning one score per row.
+    fn score_batch(&mut self, features: &Array2<f32>) -> Result<Vec<f32>, Box<dyn Error>>;
+}
+
+/// Registers custom operator shared libraries (a comma-separated list of
+/// paths) on an ONNX Runtime session builder before the model is
+/// committed. Some production DeFi scoring models ship custom ops (e.g.
+/// quantized matmuls) as separate shared libraries that must be
+/// registered ahead of session creation.
+pub fn load_custom_op(
+    mut builder: ort::session::builder::SessionBuilder,
+    lib_paths: &str,
+) -> Result<ort::session::builder::SessionBuilder, Box<dyn Error>> {
+    for lib_path in lib_paths.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        builder = builder.with_operator_library(lib_path)?;
+    }
+    Ok(builder)
+}
+
+/// [`ModelBackend`] backed by ONNX Runtime (`ort`).
+pub struct OnnxBackend {
+    session: Session,
+}
+
+impl OnnxBackend {
+    /// Loads an ONNX model, first registering any custom operator shared
+    /// libraries named in `custom_op_libs` (comma-separated) on the
+    /// session builder.
+    pub fn load_with_custom_ops(path: &Path, custom_op_libs: &str) -> Result<Self, Box<dyn Error>> {
+        let builder = load_custom_op(Session::builder()?, custom_op_libs)?;
+        let session = builder.commit_from_file(path)?;
+        Ok(OnnxBackend { session })
+    }
+}
+
+impl ModelBackend for OnnxBackend {
+    fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let session = Session::builder()?.commit_from_file(path)?;
+        Ok(OnnxBackend { session })
+    }
+
+    fn score_batch(&mut self, features: &Array2<f32>) -> Result<Vec<f32>, Box<dyn Error>> {
+        let input_tensor = Value::from_array(features.clone())?;
+        let outputs = self.session.run(ort::inputs![input_tensor])?;
+        let (_shape, data) = outputs[0].try_extract_tensor::<f32>()?;
+        Ok(data.iter().copied().take(features.nrows()).collect())
+    }
+}
+
+/// [`ModelBackend`] backed by a TensorFlow `SavedModel`, loaded via the
+/// `tensorflow` crate's `Session::from_saved_model`.
+pub struct TensorFlowBackend {
+    graph: tensorflow::Graph,
+    session: tensorflow::Session,
+}
+
+impl TensorFlowBackend {
+    /// Name of the input/output ops in the default serving signature.
+    /// Models exported with a custom signature will need a different
+    /// entry point; this covers the common `tf.saved_model.save` default.
+    const INPUT_OP: &'static str = "serving_default_input";
+    const OUTPUT_OP: &'static str = "StatefulPartitionedCall";
+}
+
+impl ModelBackend for TensorFlowBackend {
+    fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut graph = tensorflow::Graph::new();
+        let session = tensorflow::Session::from_saved_model(
+            &tensorflow::SessionOptions::new(),
+            &["serve"],
+            &mut graph,
+            path,
+        )?;
+        Ok(TensorFlowBackend { graph, session })
+    }
+
+    fn score_batch(&mut self, features: &Array2<f32>) -> Result<Vec<f32>, Box<dyn Error>> {
+        let (rows, cols) = features.dim();
+        let flat: Vec<f32> = features.iter().copied().collect();
+        let input_tensor = tensorflow::Tensor::new(&[rows as u64, cols as u64]).with_values(&flat)?;
+
+        let input_op = self.graph.operation_by_name_required(Self::INPUT_OP)?;
+        let output_op = self.graph.operation_by_name_required(Self::OUTPUT_OP)?;
+
+        let mut run_args = tensorflow::SessionRunArgs::new();
+        run_args.add_feed(&input_op, 0, &input_tensor);
+        let output_token = run_args.request_fetch(&output_op, 0);
+
+        self.session.run(&mut run_args)?;
+        let output_tensor: tensorflow::Tensor<f32> = run_args.fetch(output_token)?;
+        Ok(output_tensor.iter().copied().take(rows).collect())
+    }
+}
+
+/// Picks a [`ModelBackend`] implementation from `path`'s file extension:
+/// `.onnx` loads [`OnnxBackend`], anything else (including extensionless
+/// SavedModel directories) loads [`TensorFlowBackend`].
+pub fn load_backend(path: &Path) -> Result<Box<dyn ModelBackend>, Box<dyn Error>> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("onnx") => Ok(Box::new(OnnxBackend::load(path)?)),
+        _ => Ok(Box::new(TensorFlowBackend::load(path)?)),
+    }
+}
+
+/// Scores every pool in one `ModelBackend::score_batch` call, stacking
+/// features in the same order as `pools` and returning one score per pool
+/// in that same order (pairing scores with `Pool`s is left to the caller).
+pub(crate) fn score_pools(
+    backend: &mut dyn ModelBackend,
+    pools: &[Pool],
+) -> Result<Vec<f32>, Box<dyn Error>> {
+    if pools.is_empty() {
+        return Ok(Vec::new());
+    }
+    let features = features_matrix(pools)?;
+    backend.score_batch(&features)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn onnx_model_path_extension_is_read_as_onnx() {
+        let path = Path::new("model.onnx");
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("onnx"));
+    }
+
+    #[test]
+    fn features_matrix_matches_input_order() {
+        let pools = vec![
+            Pool {
+                dexName: "A".to_string(),
+                chain: "C".to_string(),
+                token0: "0x1".to_string(),
+                token1: "0x2".to_string(),
+                reserve0: 10,
+                reserve1: 20,
+                fee: 30,
+            },
+            Pool {
+                dexName: "B".to_string(),
+                chain: "C".to_string(),
+                token0: "0x3".to_string(),
+                token1: "0x4".to_string(),
+                reserve0: 40,
+                reserve1: 50,
+                fee: 60,
+            },
+        ];
+        let matrix = features_matrix(&pools).unwrap();
+        assert_eq!(matrix.dim(), (2, 3));
+        assert_eq!(matrix.row(0).to_vec(), vec![10.0, 20.0, 30.0]);
+        assert_eq!(matrix.row(1).to_vec(), vec![40.0, 50.0, 60.0]);
+    }
+}
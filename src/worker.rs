@@ -0,0 +1,105 @@
+use std::thread;
+
+/// A minimal thread pool for partitioning slice work across CPUs, modeled
+/// on the classic `multicore::Worker` split-by-chunks pattern: the input
+/// slice is divided into at most `num_threads` contiguous chunks, each chunk is
+/// processed on its own scoped thread, and the per-chunk outputs are
+/// concatenated back in the original chunk order. Because chunks are
+/// contiguous and never reordered, callers get a stable, deterministic
+/// result regardless of which thread happens to finish first.
+pub struct Worker {
+    num_threads: usize,
+}
+
+impl Worker {
+    /// Creates a worker pool sized to `num_threads`. A count of `0` picks
+    /// the number of logical CPUs available on the host.
+    pub fn new(num_threads: usize) -> Self {
+        let num_threads = if num_threads == 0 {
+            thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+        } else {
+            num_threads
+        };
+        Worker { num_threads }
+    }
+
+    /// The number of worker threads this pool will use.
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    fn chunk_size(&self, len: usize) -> usize {
+        ((len + self.num_threads - 1) / self.num_threads).max(1)
+    }
+
+    /// Splits `items` into contiguous chunks (one per thread) and runs `f`
+    /// on each chunk in parallel, concatenating the per-chunk results back
+    /// in input order. Falls back to calling `f` inline, with no thread
+    /// spawned, when the pool has a single thread or `items` is empty.
+    pub fn map_chunks<T, R, F>(&self, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&[T]) -> Vec<R> + Sync,
+    {
+        if items.is_empty() || self.num_threads <= 1 {
+            return f(items);
+        }
+
+        let chunk_size = self.chunk_size(items.len());
+        let chunks: Vec<&[T]> = items.chunks(chunk_size).collect();
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = chunks.iter().map(|chunk| scope.spawn(|| f(chunk))).collect();
+            handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+        })
+    }
+
+    /// Same as [`Worker::map_chunks`], but for work that can fail. The
+    /// first chunk error encountered (in chunk order) is returned.
+    pub fn try_map_chunks<T, R, E, F>(&self, items: &[T], f: F) -> Result<Vec<R>, E>
+    where
+        T: Sync,
+        R: Send,
+        E: Send,
+        F: Fn(&[T]) -> Result<Vec<R>, E> + Sync,
+    {
+        if items.is_empty() || self.num_threads <= 1 {
+            return f(items);
+        }
+
+        let chunk_size = self.chunk_size(items.len());
+        let chunks: Vec<&[T]> = items.chunks(chunk_size).collect();
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = chunks.iter().map(|chunk| scope.spawn(|| f(chunk))).collect();
+            let mut results = Vec::with_capacity(items.len());
+            for handle in handles {
+                results.extend(handle.join().unwrap()?);
+            }
+            Ok(results)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_chunks_preserves_order_single_thread() {
+        let worker = Worker::new(1);
+        let items = vec![1, 2, 3, 4, 5];
+        let doubled = worker.map_chunks(&items, |chunk| chunk.iter().map(|n| n * 2).collect());
+        assert_eq!(doubled, vec![2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn map_chunks_preserves_order_multi_thread() {
+        let worker = Worker::new(4);
+        let items: Vec<i32> = (0..97).collect();
+        let doubled = worker.map_chunks(&items, |chunk| chunk.iter().map(|n| n * 2).collect());
+        let expected: Vec<i32> = items.iter().map(|n| n * 2).collect();
+        assert_eq!(doubled, expected);
+    }
+}